@@ -35,24 +35,145 @@
 //! in reference to that concrete type if it is known at the call site.
 //! <!-- snip -->
 
+/// A runtime-reflectable tag identifying which [`Convention`] is in play.
+///
+/// Unlike [`Convention`] itself, which is a compile-time-only, sealed marker, a `ConventionKind` can
+/// be inspected, compared, parsed from a string, and printed at runtime — the same way a
+/// machine-level `CallingConvention` type enumerates the ABIs a compiler backend supports. This lets
+/// [`Convention::kind`] expose which convention a type-level `C: Convention` resolves to, and lets
+/// [`convert_dyn`] pick a [`Convert`] row from a value read at startup rather than known at compile
+/// time.
+///
+/// Marked `#[non_exhaustive]` because further conventions (beyond [`Shared`], [`Local`], and
+/// [`Cow`]) can be added to the crate without that being a breaking change to this enum's variant
+/// list.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConventionKind {
+    /// Identifies the [`Val`] convention.
+    Val,
+    /// Identifies the [`Ref`] convention.
+    Ref,
+    /// Identifies the [`Mut`] convention.
+    Mut,
+    /// Identifies the [`Shared`] convention.
+    Shared,
+    /// Identifies the [`Local`] convention.
+    Local,
+    /// Identifies the [`Cow`] convention.
+    Cow,
+}
+
+impl ::std::fmt::Display for ConventionKind {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.write_str(match self {
+            ConventionKind::Val => "val",
+            ConventionKind::Ref => "ref",
+            ConventionKind::Mut => "mut",
+            ConventionKind::Shared => "shared",
+            ConventionKind::Local => "local",
+            ConventionKind::Cow => "cow",
+        })
+    }
+}
+
+/// The error returned when parsing a [`ConventionKind`] from a string that names no known
+/// convention.
+///
+/// # Examples
+///
+/// ```
+/// use call_by::ConventionKind;
+///
+/// let kind: ConventionKind = "Ref".parse().unwrap();
+/// assert_eq!(kind, ConventionKind::Ref);
+///
+/// assert!("borrowed".parse::<ConventionKind>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseConventionKindError(String);
+
+impl ::std::fmt::Display for ParseConventionKindError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{:?} is not a known calling convention", self.0)
+    }
+}
+
+impl ::std::error::Error for ParseConventionKindError {}
+
+impl ::std::str::FromStr for ConventionKind {
+    type Err = ParseConventionKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "val" => Ok(ConventionKind::Val),
+            "ref" => Ok(ConventionKind::Ref),
+            "mut" => Ok(ConventionKind::Mut),
+            "shared" => Ok(ConventionKind::Shared),
+            "local" => Ok(ConventionKind::Local),
+            "cow" => Ok(ConventionKind::Cow),
+            _ => Err(ParseConventionKindError(s.to_string())),
+        }
+    }
+}
+
 /// There are three fundamental ways to pass a `T` as input or return a `T` as output: by [`Val`]ue,
 /// by shared immutable [`Ref`]erence, and by unique [`Mut`]able reference.
 ///
 /// This is a sealed trait, implemented for all three of these conventions.
 pub trait Convention: sealed::Convention + Sized {
     const TOKEN: Self;
+
+    /// The runtime-reflectable [`ConventionKind`] for this convention.
+    fn kind() -> ConventionKind;
 }
 
 impl Convention for Val {
     const TOKEN: Self = Val;
+
+    fn kind() -> ConventionKind {
+        ConventionKind::Val
+    }
 }
 
 impl Convention for Ref {
     const TOKEN: Self = Ref;
+
+    fn kind() -> ConventionKind {
+        ConventionKind::Ref
+    }
 }
 
 impl Convention for Mut {
     const TOKEN: Self = Mut;
+
+    fn kind() -> ConventionKind {
+        ConventionKind::Mut
+    }
+}
+
+impl Convention for Shared {
+    const TOKEN: Self = Shared;
+
+    fn kind() -> ConventionKind {
+        ConventionKind::Shared
+    }
+}
+
+impl Convention for Local {
+    const TOKEN: Self = Local;
+
+    fn kind() -> ConventionKind {
+        ConventionKind::Local
+    }
+}
+
+impl Convention for Cow {
+    const TOKEN: Self = Cow;
+
+    fn kind() -> ConventionKind {
+        ConventionKind::Cow
+    }
 }
 
 /// To get the type of `T` via calling convention `Convention`, write `<T as By<'a,
@@ -139,6 +260,171 @@ impl<'a, T: 'a + ?Sized> By<'a, Mut> for T {
     }
 }
 
+/// Taking a `T` by [`Shared`] reference-counted ownership means taking an `Arc<T>` as input to or
+/// output from a function.
+///
+/// # Examples
+///
+/// ```
+/// use call_by::*;
+/// use std::sync::Arc;
+///
+/// let shared: Arc<String> = <String as Convert<Val, Shared>>::convert("hi".to_string());
+/// let other = shared.clone();
+///
+/// // Converting `Shared` to `Val` clones the underlying value rather than unwrapping the `Arc`,
+/// // since other handles may still be holding onto it.
+/// let owned: String = <String as Convert<Shared, Val>>::convert(shared);
+/// assert_eq!(owned, "hi");
+/// assert_eq!(*other, "hi");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Shared;
+
+impl<'a, T> By<'a, Shared> for T {
+    type Type = ::std::sync::Arc<T>;
+
+    fn copy(this: Self::Type) -> Self
+    where
+        Self: Copy,
+    {
+        *this
+    }
+
+    fn clone(this: Self::Type) -> Self
+    where
+        Self: Clone,
+    {
+        (*this).clone()
+    }
+}
+
+/// Taking a `T` by [`Local`] reference-counted ownership means taking an `Rc<T>` as input to or
+/// output from a function.
+///
+/// # Examples
+///
+/// ```
+/// use call_by::*;
+/// use std::rc::Rc;
+///
+/// let local: Rc<String> = <String as Convert<Val, Local>>::convert("hi".to_string());
+/// let other = local.clone();
+///
+/// // Converting `Local` to `Val` clones the underlying value rather than unwrapping the `Rc`,
+/// // since other handles may still be holding onto it.
+/// let owned: String = <String as Convert<Local, Val>>::convert(local);
+/// assert_eq!(owned, "hi");
+/// assert_eq!(*other, "hi");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Local;
+
+impl<'a, T> By<'a, Local> for T {
+    type Type = ::std::rc::Rc<T>;
+
+    fn copy(this: Self::Type) -> Self
+    where
+        Self: Copy,
+    {
+        *this
+    }
+
+    fn clone(this: Self::Type) -> Self
+    where
+        Self: Clone,
+    {
+        (*this).clone()
+    }
+}
+
+/// Taking a `T` by [`Cow`] means taking a `std::borrow::Cow<'a, T>` as input to or output from a
+/// function, deferring the choice between borrowing and owning to whichever side of the call
+/// actually needs it.
+///
+/// # Examples
+///
+/// ```
+/// use call_by::*;
+/// use std::borrow::Cow as StdCow;
+///
+/// let owned: StdCow<String> = <String as Convert<Val, Cow>>::convert("hi".to_string());
+/// let back: String = <String as Convert<Cow, Val>>::convert(owned);
+/// assert_eq!(back, "hi");
+///
+/// let s = "hi".to_string();
+/// let borrowed: StdCow<String> = <String as Convert<Ref, Cow>>::convert(&s);
+/// let back: String = <String as Convert<Cow, Val>>::convert(borrowed);
+/// assert_eq!(back, "hi");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Cow;
+
+impl<'a, T: 'a + ToOwned + ?Sized> By<'a, Cow> for T {
+    type Type = ::std::borrow::Cow<'a, T>;
+
+    fn copy(this: Self::Type) -> Self
+    where
+        Self: Copy,
+    {
+        match this {
+            ::std::borrow::Cow::Borrowed(r) => *r,
+            // `Self: Copy` implies `Self: Clone`, so `Self`'s only reachable `ToOwned` impl is the
+            // standard blanket one, under which `<Self as ToOwned>::Owned` is `Self` itself; the
+            // type system can't see that equality through the associated type, so we help it along
+            // the same way `coerce_move` does.
+            ::std::borrow::Cow::Owned(owned) => unsafe {
+                let val =
+                    ::std::ptr::read(&owned as *const <T as ToOwned>::Owned as *const Self);
+                ::std::mem::forget(owned);
+                val
+            },
+        }
+    }
+
+    fn clone(this: Self::Type) -> Self
+    where
+        Self: Clone,
+    {
+        let owned = this.into_owned();
+        unsafe {
+            let val = ::std::ptr::read(&owned as *const <T as ToOwned>::Owned as *const Self);
+            ::std::mem::forget(owned);
+            val
+        }
+    }
+}
+
+/// Apply a function to a thing of unknown calling convention by borrowing it, regardless of whether
+/// it is held by [`Val`]ue, [`Ref`]erence, or [`Mut`]able reference.
+///
+/// This mirrors the ergonomic `.map(|&data| ...)` pattern used to transform a payload in place: no
+/// matter which convention a caller's `T` happens to be stored under, `map_by` can always borrow a
+/// `&T` out of it and hand that to `f`, returning the owned result.
+///
+/// # Examples
+///
+/// ```
+/// use call_by::*;
+///
+/// let a: usize = map_by::<String, _, Val, _>("hello".to_string(), |s| s.len());
+/// let b: usize = map_by::<String, _, Ref, _>(&"hello".to_string(), |s| s.len());
+/// let c: usize = map_by::<String, _, Mut, _>(&mut "hello".to_string(), |s| s.len());
+///
+/// assert_eq!(a, 5);
+/// assert_eq!(b, 5);
+/// assert_eq!(c, 5);
+/// ```
+pub fn map_by<'a, T, U, C, F>(this: <T as By<'a, C>>::Type, f: F) -> U
+where
+    T: By<'a, C>,
+    T: sealed::Borrow<'a, C>,
+    C: Convention,
+    F: FnOnce(&T) -> U,
+{
+    f(sealed::Borrow::borrow(&this))
+}
+
 /// Convert between different calling conventions.
 ///
 /// Only some conversions are sensible in Rust, due to the ownership system. These are the valid
@@ -216,6 +502,171 @@ impl<'a, T: 'a> Convert<'a, Mut, Mut> for T {
     }
 }
 
+impl<'a, T> Convert<'a, Val, Shared> for T {
+    fn convert(from: T) -> ::std::sync::Arc<T> {
+        ::std::sync::Arc::new(from)
+    }
+}
+
+impl<'a, T> Convert<'a, Val, Local> for T {
+    fn convert(from: T) -> ::std::rc::Rc<T> {
+        ::std::rc::Rc::new(from)
+    }
+}
+
+impl<'a, T: 'a + Clone> Convert<'a, Ref, Shared> for T {
+    fn convert(from: &T) -> ::std::sync::Arc<T> {
+        ::std::sync::Arc::new(from.clone())
+    }
+}
+
+impl<'a, T: 'a + Clone> Convert<'a, Ref, Local> for T {
+    fn convert(from: &T) -> ::std::rc::Rc<T> {
+        ::std::rc::Rc::new(from.clone())
+    }
+}
+
+impl<'a, T: 'a + Clone> Convert<'a, Shared, Val> for T {
+    fn convert(from: ::std::sync::Arc<T>) -> T {
+        (*from).clone()
+    }
+}
+
+impl<'a, T: 'a + Clone> Convert<'a, Local, Val> for T {
+    fn convert(from: ::std::rc::Rc<T>) -> T {
+        (*from).clone()
+    }
+}
+
+impl<'a, T: 'a + ToOwned + ?Sized> Convert<'a, Ref, Cow> for T {
+    fn convert(from: &'a T) -> ::std::borrow::Cow<'a, T> {
+        ::std::borrow::Cow::Borrowed(from)
+    }
+}
+
+impl<'a, T: 'a + Clone> Convert<'a, Val, Cow> for T {
+    fn convert(from: T) -> ::std::borrow::Cow<'a, T> {
+        ::std::borrow::Cow::Owned(from)
+    }
+}
+
+impl<'a, T: 'a + Clone> Convert<'a, Cow, Val> for T {
+    fn convert(from: ::std::borrow::Cow<'a, T>) -> T {
+        from.into_owned()
+    }
+}
+
+// Note: there is deliberately no `Convert<'a, Cow, Ref>` impl. A `Cow::Owned` value has no `&'a T`
+// to hand back without leaking the allocation — `Deref` only ties the reference to the `Cow`'s own
+// borrow, not to `'a`. Use `Convert<Cow, Val>` followed by a local borrow instead.
+
+/// The fallible counterpart to [`Convert`]: convert between different calling conventions where the
+/// underlying conversion of the value itself might fail.
+///
+/// Just as [`TryFrom`]/[`TryInto`] pair with [`From`]/[`Into`] in the standard library, this trait
+/// pairs with [`Convert`]. The conversions that don't need to touch the underlying value (`Ref` →
+/// `Ref`, `Mut` → `Ref`, `Mut` → `Mut`) remain infallible and simply wrap their result in [`Ok`]; the
+/// conversions that must produce an owned value (anything → [`Val`]) go through [`TryInto`], after a
+/// [`Clone`] when the input is borrowed, so that the fallibility of the underlying type's conversion
+/// is preserved rather than papered over.
+///
+/// # Examples
+///
+/// ```
+/// use call_by::*;
+///
+/// let a: u8 = <u8 as TryConvert<Val, Val>>::try_convert(1).unwrap();
+/// let b: u8 = <u8 as TryConvert<Ref, Val>>::try_convert(&2).unwrap();
+/// let c: u8 = <u8 as TryConvert<Mut, Val>>::try_convert(&mut 3).unwrap();
+///
+/// let d: &u8 = <u8 as TryConvert<Ref, Ref>>::try_convert(&4).unwrap();
+/// let mut five = 5;
+/// let e: &u8 = <u8 as TryConvert<Mut, Ref>>::try_convert(&mut five).unwrap();
+///
+/// let mut six = 6;
+/// let f: &mut u8 = <u8 as TryConvert<Mut, Mut>>::try_convert(&mut six).unwrap();
+///
+/// assert_eq!(a, 1);
+/// assert_eq!(b, 2);
+/// assert_eq!(c, 3);
+/// assert_eq!(*d, 4);
+/// assert_eq!(*e, 5);
+/// assert_eq!(*f, 6);
+/// ```
+pub trait TryConvert<'a, From: Convention, To: Convention>
+where
+    Self: By<'a, To> + By<'a, From>,
+{
+    /// The error produced when the underlying conversion fails.
+    type Error;
+
+    /// Attempt to convert from one calling convention to another.
+    #[allow(clippy::wrong_self_convention)]
+    fn try_convert(
+        from: <Self as By<'a, From>>::Type,
+    ) -> Result<<Self as By<'a, To>>::Type, Self::Error>;
+}
+
+impl<'a, T> TryConvert<'a, Val, Val> for T
+where
+    T: ::std::convert::TryInto<T>,
+{
+    type Error = <T as ::std::convert::TryInto<T>>::Error;
+
+    #[allow(clippy::useless_conversion)]
+    fn try_convert(from: T) -> Result<T, Self::Error> {
+        from.try_into()
+    }
+}
+
+impl<'a, T: 'a + Clone> TryConvert<'a, Ref, Val> for T
+where
+    T: ::std::convert::TryInto<T>,
+{
+    type Error = <T as ::std::convert::TryInto<T>>::Error;
+
+    #[allow(clippy::useless_conversion)]
+    fn try_convert(from: &T) -> Result<T, Self::Error> {
+        from.clone().try_into()
+    }
+}
+
+impl<'a, T: 'a + Clone> TryConvert<'a, Mut, Val> for T
+where
+    T: ::std::convert::TryInto<T>,
+{
+    type Error = <T as ::std::convert::TryInto<T>>::Error;
+
+    #[allow(clippy::useless_conversion)]
+    fn try_convert(from: &mut T) -> Result<T, Self::Error> {
+        Clone::clone(from).try_into()
+    }
+}
+
+impl<'a, T: 'a> TryConvert<'a, Ref, Ref> for T {
+    type Error = ::std::convert::Infallible;
+
+    fn try_convert(from: &T) -> Result<&T, Self::Error> {
+        Ok(from)
+    }
+}
+
+impl<'a, T: 'a> TryConvert<'a, Mut, Ref> for T {
+    type Error = ::std::convert::Infallible;
+
+    fn try_convert(from: &mut T) -> Result<&T, Self::Error> {
+        Ok(&*from)
+    }
+}
+
+impl<'a, T: 'a> TryConvert<'a, Mut, Mut> for T {
+    type Error = ::std::convert::Infallible;
+
+    fn try_convert(from: &mut T) -> Result<&mut T, Self::Error> {
+        Ok(from)
+    }
+}
+
 /// The generalization of [`Into`], [`AsRef`], and [`AsMut`]: in a calling-convention polymorphic
 /// context, this trait allows you to invoke the appropriate conversion method depending on the
 /// applicable calling convention.
@@ -267,6 +718,65 @@ where
     }
 }
 
+/// The fallible counterpart to [`As`]: the generalization of [`TryInto`], [`AsRef`], and [`AsMut`].
+///
+/// The [`Val`] case defers to [`TryInto`], so it can fail; the [`Ref`] and [`Mut`] cases defer to
+/// [`AsRef`] and [`AsMut`] as before, which are infallible and so are wrapped in [`Ok`].
+///
+/// # Examples
+///
+/// ```
+/// use call_by::*;
+///
+/// let a: u8 = <i32 as TryAs<Val, u8>>::try_as_convention(10).unwrap();
+/// assert_eq!(a, 10);
+///
+/// // A failing `TryInto` propagates through `try_as_convention` rather than being papered over.
+/// assert!(<i32 as TryAs<Val, u8>>::try_as_convention(1000).is_err());
+/// ```
+pub trait TryAs<'a, C: Convention, T: By<'a, C>>: By<'a, C> {
+    /// The error produced when the underlying conversion fails.
+    type Error;
+
+    #[allow(clippy::wrong_self_convention)]
+    fn try_as_convention(
+        this: <Self as By<'a, C>>::Type,
+    ) -> Result<<T as By<'a, C>>::Type, Self::Error>;
+}
+
+impl<'a, T, S> TryAs<'a, Val, T> for S
+where
+    S: ::std::convert::TryInto<T>,
+{
+    type Error = S::Error;
+
+    fn try_as_convention(this: S) -> Result<T, Self::Error> {
+        this.try_into()
+    }
+}
+
+impl<'a, T: 'a, S: 'a> TryAs<'a, Ref, T> for S
+where
+    S: AsRef<T>,
+{
+    type Error = ::std::convert::Infallible;
+
+    fn try_as_convention(this: &S) -> Result<&T, Self::Error> {
+        Ok(this.as_ref())
+    }
+}
+
+impl<'a, T: 'a, S: 'a> TryAs<'a, Mut, T> for S
+where
+    S: AsMut<T>,
+{
+    type Error = ::std::convert::Infallible;
+
+    fn try_as_convention(this: &mut S) -> Result<&mut T, Self::Error> {
+        Ok(this.as_mut())
+    }
+}
+
 /// Sometimes, Rust can't see through the lifetime. You can use this function to safely convince
 /// Rust that `<T as By<'a, Val>>::Type` is `T`.
 pub fn coerce_move<'a, T: By<'a, Val>>(by_val: T::Type) -> T {
@@ -289,6 +799,109 @@ pub fn coerce_mut<'a, T: By<'a, Mut>>(by_mut: T::Type) -> &'a mut T {
     unsafe { ::std::ptr::read(&by_mut as *const <T as By<'a, Mut>>::Type as *const &'a mut T) }
 }
 
+/// Sometimes, Rust can't see through the lifetime. You can use this function to safely convince
+/// Rust that `<T as By<'a, Shared>>::Type` is `Arc<T>`.
+pub fn coerce_shared<'a, T: By<'a, Shared>>(by_arc: T::Type) -> ::std::sync::Arc<T> {
+    unsafe {
+        let val = ::std::ptr::read(
+            &by_arc as *const <T as By<'a, Shared>>::Type as *const ::std::sync::Arc<T>,
+        );
+        ::std::mem::forget(by_arc);
+        val
+    }
+}
+
+/// Sometimes, Rust can't see through the lifetime. You can use this function to safely convince
+/// Rust that `<T as By<'a, Local>>::Type` is `Rc<T>`.
+pub fn coerce_local<'a, T: By<'a, Local>>(by_rc: T::Type) -> ::std::rc::Rc<T> {
+    unsafe {
+        let val = ::std::ptr::read(
+            &by_rc as *const <T as By<'a, Local>>::Type as *const ::std::rc::Rc<T>,
+        );
+        ::std::mem::forget(by_rc);
+        val
+    }
+}
+
+/// A value held under one of the three basic calling conventions, erased to a runtime-inspectable
+/// form suitable for crossing a `dyn`-friendly boundary.
+///
+/// This is the payload type [`convert_dyn`] operates over, so that a [`ConventionKind`] read from a
+/// config string can select which [`Convert`] row applies, rather than that choice being fixed by
+/// the type-level `C: Convention` parameter.
+pub enum DynValue<'a, T> {
+    /// A value held by [`Val`]ue.
+    Val(T),
+    /// A value held by [`Ref`]erence.
+    Ref(&'a T),
+    /// A value held by [`Mut`]able reference.
+    Mut(&'a mut T),
+}
+
+/// The error returned by [`convert_dyn`] when no [`Convert`] row connects the requested pair of
+/// [`ConventionKind`]s, or when the supplied [`DynValue`] doesn't match `kind_from`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedConversionError {
+    from: ConventionKind,
+    to: ConventionKind,
+}
+
+impl ::std::fmt::Display for UnsupportedConversionError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "no conversion from {} to {}", self.from, self.to)
+    }
+}
+
+impl ::std::error::Error for UnsupportedConversionError {}
+
+/// Apply the [`Convert`] row selected by a pair of runtime [`ConventionKind`]s, bridging the
+/// compile-time type-level machinery of [`Convert`] to behavior configured at startup — for
+/// instance, from a config string parsed via [`ConventionKind`]'s [`FromStr`](::std::str::FromStr)
+/// impl.
+///
+/// Only the rows [`Convert`] implements among [`Val`], [`Ref`], and [`Mut`] are reachable here;
+/// `kind_from`/`kind_to` pairs naming [`Shared`], [`Local`], or [`Cow`], or a `value` that doesn't
+/// match `kind_from`, return [`UnsupportedConversionError`].
+///
+/// # Examples
+///
+/// ```
+/// use call_by::*;
+///
+/// let value = DynValue::Ref(&5);
+/// match convert_dyn(ConventionKind::Ref, ConventionKind::Val, value) {
+///     Ok(DynValue::Val(v)) => assert_eq!(v, 5),
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn convert_dyn<'a, T: 'a + Clone>(
+    kind_from: ConventionKind,
+    kind_to: ConventionKind,
+    value: DynValue<'a, T>,
+) -> Result<DynValue<'a, T>, UnsupportedConversionError> {
+    match (kind_from, kind_to, value) {
+        (ConventionKind::Val, ConventionKind::Val, DynValue::Val(v)) => {
+            Ok(DynValue::Val(<T as Convert<Val, Val>>::convert(v)))
+        }
+        (ConventionKind::Ref, ConventionKind::Val, DynValue::Ref(v)) => {
+            Ok(DynValue::Val(<T as Convert<Ref, Val>>::convert(v)))
+        }
+        (ConventionKind::Mut, ConventionKind::Val, DynValue::Mut(v)) => {
+            Ok(DynValue::Val(<T as Convert<Mut, Val>>::convert(v)))
+        }
+        (ConventionKind::Ref, ConventionKind::Ref, DynValue::Ref(v)) => {
+            Ok(DynValue::Ref(<T as Convert<Ref, Ref>>::convert(v)))
+        }
+        (ConventionKind::Mut, ConventionKind::Ref, DynValue::Mut(v)) => {
+            Ok(DynValue::Ref(<T as Convert<Mut, Ref>>::convert(v)))
+        }
+        (ConventionKind::Mut, ConventionKind::Mut, DynValue::Mut(v)) => {
+            Ok(DynValue::Mut(<T as Convert<Mut, Mut>>::convert(v)))
+        }
+        (from, to, _) => Err(UnsupportedConversionError { from, to }),
+    }
+}
+
 mod sealed {
     use super::*;
 
@@ -296,4 +909,53 @@ mod sealed {
     impl Convention for Val {}
     impl Convention for Ref {}
     impl Convention for Mut {}
+    impl Convention for Shared {}
+    impl Convention for Local {}
+    impl Convention for Cow {}
+
+    /// Borrow a thing of unknown calling convention as a shared reference.
+    ///
+    /// This is an implementation detail that powers [`map_by`](super::map_by); it lets the six
+    /// conventions be dispatched by type rather than by runtime branching, since each of them can
+    /// hand out a `&Self` regardless of whether it owns, borrows, or mutably borrows the underlying
+    /// value. Sealed because it's not meaningful to callers outside this crate.
+    pub trait Borrow<'a, C: super::Convention>: super::By<'a, C> {
+        fn borrow(this: &Self::Type) -> &Self;
+    }
+
+    impl<'a, T> Borrow<'a, super::Val> for T {
+        fn borrow(this: &Self::Type) -> &Self {
+            this
+        }
+    }
+
+    impl<'a, T: 'a + ?Sized> Borrow<'a, super::Ref> for T {
+        fn borrow(this: &Self::Type) -> &Self {
+            this
+        }
+    }
+
+    impl<'a, T: 'a + ?Sized> Borrow<'a, super::Mut> for T {
+        fn borrow(this: &Self::Type) -> &Self {
+            this
+        }
+    }
+
+    impl<'a, T> Borrow<'a, super::Shared> for T {
+        fn borrow(this: &Self::Type) -> &Self {
+            this
+        }
+    }
+
+    impl<'a, T> Borrow<'a, super::Local> for T {
+        fn borrow(this: &Self::Type) -> &Self {
+            this
+        }
+    }
+
+    impl<'a, T: 'a + ToOwned + ?Sized> Borrow<'a, super::Cow> for T {
+        fn borrow(this: &Self::Type) -> &Self {
+            this
+        }
+    }
 }